@@ -27,15 +27,23 @@ use crate::{
 use arrow::{
     array::*,
     compute::cast,
-    datatypes::{DataType, TimeUnit},
+    datatypes::{DataType, IntervalUnit, TimeUnit},
     scalar::PrimitiveScalar,
-    types::NativeType,
+    types::{days_ms, months_days_ns, NativeType},
 };
-use arrow::{compute::temporal, temporal_conversions::timestamp_ns_to_datetime};
-use chrono::prelude::{DateTime, Utc};
+use arrow::{
+    compute::temporal,
+    temporal_conversions::{
+        timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_s_to_datetime,
+        timestamp_us_to_datetime,
+    },
+};
+use chrono::prelude::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use chrono::Datelike;
 use chrono::Duration;
 use chrono::Timelike;
+use chrono::{FixedOffset, LocalResult, TimeZone};
+use chrono_tz::Tz;
 use std::borrow::Borrow;
 
 /// given a function `op` that maps a `&str` to a Result of an arrow native type,
@@ -128,8 +136,295 @@ fn string_to_timestamp_nanos_shim(s: &str) -> Result<i64> {
     string_to_timestamp_nanos(s).map_err(|e| e.into())
 }
 
+/// Parses `s` with a single chrono `strftime` pattern, returning the instant
+/// as nanoseconds since the epoch. A pattern carrying an explicit offset
+/// (`%z`/`%:z`/`%#z`, or the RFC3339 shortcut `%+`) is parsed as a
+/// timezone-aware `DateTime`; otherwise the value is read as a naive (UTC)
+/// instant.
+fn string_to_timestamp_nanos_formatted(s: &str, format: &str) -> Result<i64> {
+    if format.contains("%z")
+        || format.contains("%:z")
+        || format.contains("%#z")
+        || format.contains("%+")
+    {
+        Ok(DateTime::parse_from_str(s, format)
+            .map_err(|e| {
+                DataFusionError::Execution(format!(
+                    "Error parsing '{}' as timestamp using format '{}': {}",
+                    s, format, e
+                ))
+            })?
+            .timestamp_nanos())
+    } else {
+        Ok(NaiveDateTime::parse_from_str(s, format)
+            .map_err(|e| {
+                DataFusionError::Execution(format!(
+                    "Error parsing '{}' as timestamp using format '{}': {}",
+                    s, format, e
+                ))
+            })?
+            .timestamp_nanos())
+    }
+}
+
+/// Tries each of `formats` in order, returning the first successful parse (as
+/// nanoseconds) and erroring only when every format fails.
+fn string_to_timestamp_nanos_with_formats(s: &str, formats: &[&str]) -> Result<i64> {
+    let mut last_err = None;
+    for format in formats {
+        match string_to_timestamp_nanos_formatted(s, format) {
+            Ok(n) => return Ok(n),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        DataFusionError::Execution(format!("No format provided to parse '{}'", s))
+    }))
+}
+
+/// Collects the format strings applicable to row `idx` from the trailing
+/// `to_timestamp` arguments. Scalar arguments apply to every row while array
+/// arguments are read positionally; null format entries are skipped.
+fn gather_formats(
+    formats: &[ColumnarValue],
+    idx: usize,
+    name: &str,
+) -> Result<Vec<String>> {
+    let mut result = Vec::with_capacity(formats.len());
+    for format in formats {
+        match format {
+            ColumnarValue::Scalar(ScalarValue::Utf8(a))
+            | ColumnarValue::Scalar(ScalarValue::LargeUtf8(a)) => {
+                if let Some(s) = a {
+                    result.push(s.clone());
+                }
+            }
+            ColumnarValue::Array(array) => match array.data_type() {
+                DataType::Utf8 => {
+                    let array =
+                        array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+                    if !array.is_null(idx) {
+                        result.push(array.value(idx).to_string());
+                    }
+                }
+                DataType::LargeUtf8 => {
+                    let array =
+                        array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+                    if !array.is_null(idx) {
+                        result.push(array.value(idx).to_string());
+                    }
+                }
+                other => {
+                    return Err(DataFusionError::Internal(format!(
+                        "Unsupported format data type {:?} for function {}",
+                        other, name
+                    )))
+                }
+            },
+            other => {
+                return Err(DataFusionError::Internal(format!(
+                    "Unsupported format argument {:?} for function {}",
+                    other, name
+                )))
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Like [`handle`] but threads the trailing `args` through as a list of chrono
+/// format strings: `op` turns a value and its formats into nanoseconds and
+/// `op2` rescales that instant into the target `TimeUnit`.
+fn handle_multiple<O, F, S>(
+    args: &[ColumnarValue],
+    op: F,
+    op2: S,
+    name: &str,
+    data_type: DataType,
+) -> Result<ColumnarValue>
+where
+    O: NativeType,
+    ScalarValue: From<Option<O>>,
+    F: Fn(&str, &[&str]) -> Result<i64>,
+    S: Fn(i64) -> O,
+{
+    let formats = &args[1..];
+
+    match &args[0] {
+        ColumnarValue::Array(a) => {
+            let parse_row = |idx: usize, s: &str| -> Result<O> {
+                let row_formats = gather_formats(formats, idx, name)?;
+                let refs: Vec<&str> = row_formats.iter().map(String::as_str).collect();
+                op(s, &refs).map(&op2)
+            };
+            let array: PrimitiveArray<O> = match a.data_type() {
+                DataType::Utf8 => a
+                    .as_any()
+                    .downcast_ref::<Utf8Array<i32>>()
+                    .ok_or_else(|| {
+                        DataFusionError::Internal(
+                            "failed to downcast to string".to_string(),
+                        )
+                    })?
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, x)| x.map(|s| parse_row(idx, s)).transpose())
+                    .collect::<Result<PrimitiveArray<O>>>()?,
+                DataType::LargeUtf8 => a
+                    .as_any()
+                    .downcast_ref::<Utf8Array<i64>>()
+                    .ok_or_else(|| {
+                        DataFusionError::Internal(
+                            "failed to downcast to string".to_string(),
+                        )
+                    })?
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, x)| x.map(|s| parse_row(idx, s)).transpose())
+                    .collect::<Result<PrimitiveArray<O>>>()?,
+                other => {
+                    return Err(DataFusionError::Internal(format!(
+                        "Unsupported data type {:?} for function {}",
+                        other, name,
+                    )))
+                }
+            };
+            Ok(ColumnarValue::Array(Arc::new(array.to(data_type))))
+        }
+        ColumnarValue::Scalar(scalar) => match scalar {
+            ScalarValue::Utf8(a) | ScalarValue::LargeUtf8(a) => Ok(match a {
+                Some(s) => {
+                    let row_formats = gather_formats(formats, 0, name)?;
+                    let refs: Vec<&str> =
+                        row_formats.iter().map(String::as_str).collect();
+                    let value = op2(op(s, &refs)?);
+                    let s = PrimitiveScalar::<O>::new(data_type, Some(value));
+                    ColumnarValue::Scalar(s.try_into()?)
+                }
+                None => ColumnarValue::Scalar(ScalarValue::new_null(data_type)),
+            }),
+            other => Err(DataFusionError::Internal(format!(
+                "Unsupported data type {:?} for function {}",
+                other, name
+            ))),
+        },
+    }
+}
+
+/// Number of decimal places a [`TimeUnit`] holds relative to seconds.
+fn timeunit_scale(unit: &TimeUnit) -> u32 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    }
+}
+
+/// Rescales a timestamp count from `from` into `to`, multiplying or dividing by
+/// the appropriate power of ten.
+fn rescale_timestamp(value: i64, from: &TimeUnit, to: &TimeUnit) -> i64 {
+    let from = timeunit_scale(from) as i32;
+    let to = timeunit_scale(to) as i32;
+    if to >= from {
+        value * 10_i64.pow((to - from) as u32)
+    } else {
+        value / 10_i64.pow((from - to) as u32)
+    }
+}
+
+/// Builds a `Timestamp` scalar of the given `unit` preserving an optional
+/// timezone.
+fn timestamp_scalar(
+    value: Option<i64>,
+    unit: &TimeUnit,
+    tz: Option<String>,
+) -> ScalarValue {
+    match unit {
+        TimeUnit::Second => ScalarValue::TimestampSecond(value, tz),
+        TimeUnit::Millisecond => ScalarValue::TimestampMillisecond(value, tz),
+        TimeUnit::Microsecond => ScalarValue::TimestampMicrosecond(value, tz),
+        TimeUnit::Nanosecond => ScalarValue::TimestampNanosecond(value, tz),
+    }
+}
+
+/// Handles the non-string inputs of the `to_timestamp*` family: `Int64` values
+/// are taken as raw epochs already expressed in `target`, while existing
+/// `Timestamp` inputs are rescaled from their own unit into `target`. Returns
+/// `Ok(None)` for string inputs so the caller falls through to the parsing
+/// path.
+fn handle_numeric(
+    args: &[ColumnarValue],
+    target: TimeUnit,
+    data_type: DataType,
+) -> Result<Option<ColumnarValue>> {
+    match &args[0] {
+        ColumnarValue::Array(a) => match a.data_type() {
+            DataType::Int64 => {
+                let array = a.as_any().downcast_ref::<Int64Array>().unwrap();
+                Ok(Some(ColumnarValue::Array(Arc::new(
+                    array.clone().to(data_type),
+                ))))
+            }
+            DataType::Timestamp(from, _) => {
+                let from = *from;
+                let array = a.as_any().downcast_ref::<Int64Array>().unwrap();
+                let array: PrimitiveArray<i64> = array
+                    .iter()
+                    .map(|x| x.map(|v| rescale_timestamp(*v, &from, &target)))
+                    .collect();
+                Ok(Some(ColumnarValue::Array(Arc::new(array.to(data_type)))))
+            }
+            _ => Ok(None),
+        },
+        ColumnarValue::Scalar(scalar) => {
+            let rescaled = |v: &Option<i64>, from: &TimeUnit, tz: &Option<String>| {
+                ColumnarValue::Scalar(timestamp_scalar(
+                    v.map(|x| rescale_timestamp(x, from, &target)),
+                    &target,
+                    tz.clone(),
+                ))
+            };
+            match scalar {
+                ScalarValue::Int64(v) => Ok(Some(ColumnarValue::Scalar(
+                    timestamp_scalar(*v, &target, None),
+                ))),
+                ScalarValue::TimestampSecond(v, tz) => {
+                    Ok(Some(rescaled(v, &TimeUnit::Second, tz)))
+                }
+                ScalarValue::TimestampMillisecond(v, tz) => {
+                    Ok(Some(rescaled(v, &TimeUnit::Millisecond, tz)))
+                }
+                ScalarValue::TimestampMicrosecond(v, tz) => {
+                    Ok(Some(rescaled(v, &TimeUnit::Microsecond, tz)))
+                }
+                ScalarValue::TimestampNanosecond(v, tz) => {
+                    Ok(Some(rescaled(v, &TimeUnit::Nanosecond, tz)))
+                }
+                _ => Ok(None),
+            }
+        }
+    }
+}
+
 /// to_timestamp SQL function
 pub fn to_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() > 1 {
+        return handle_multiple::<i64, _, _>(
+            args,
+            string_to_timestamp_nanos_with_formats,
+            |n| n,
+            "to_timestamp",
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+        );
+    }
+    if let Some(result) = handle_numeric(
+        args,
+        TimeUnit::Nanosecond,
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+    )? {
+        return Ok(result);
+    }
     handle::<i64, _>(
         args,
         string_to_timestamp_nanos_shim,
@@ -140,6 +435,22 @@ pub fn to_timestamp(args: &[ColumnarValue]) -> Result<ColumnarValue> {
 
 /// to_timestamp_millis SQL function
 pub fn to_timestamp_millis(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() > 1 {
+        return handle_multiple::<i64, _, _>(
+            args,
+            string_to_timestamp_nanos_with_formats,
+            |n| n / 1_000_000,
+            "to_timestamp_millis",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+        );
+    }
+    if let Some(result) = handle_numeric(
+        args,
+        TimeUnit::Millisecond,
+        DataType::Timestamp(TimeUnit::Millisecond, None),
+    )? {
+        return Ok(result);
+    }
     handle::<i64, _>(
         args,
         |s| string_to_timestamp_nanos_shim(s).map(|n| n / 1_000_000),
@@ -150,6 +461,22 @@ pub fn to_timestamp_millis(args: &[ColumnarValue]) -> Result<ColumnarValue> {
 
 /// to_timestamp_micros SQL function
 pub fn to_timestamp_micros(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() > 1 {
+        return handle_multiple::<i64, _, _>(
+            args,
+            string_to_timestamp_nanos_with_formats,
+            |n| n / 1_000,
+            "to_timestamp_micros",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+        );
+    }
+    if let Some(result) = handle_numeric(
+        args,
+        TimeUnit::Microsecond,
+        DataType::Timestamp(TimeUnit::Microsecond, None),
+    )? {
+        return Ok(result);
+    }
     handle::<i64, _>(
         args,
         |s| string_to_timestamp_nanos_shim(s).map(|n| n / 1_000),
@@ -160,6 +487,22 @@ pub fn to_timestamp_micros(args: &[ColumnarValue]) -> Result<ColumnarValue> {
 
 /// to_timestamp_seconds SQL function
 pub fn to_timestamp_seconds(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() > 1 {
+        return handle_multiple::<i64, _, _>(
+            args,
+            string_to_timestamp_nanos_with_formats,
+            |n| n / 1_000_000_000,
+            "to_timestamp_seconds",
+            DataType::Timestamp(TimeUnit::Second, None),
+        );
+    }
+    if let Some(result) = handle_numeric(
+        args,
+        TimeUnit::Second,
+        DataType::Timestamp(TimeUnit::Second, None),
+    )? {
+        return Ok(result);
+    }
     handle::<i64, _>(
         args,
         |s| string_to_timestamp_nanos_shim(s).map(|n| n / 1_000_000_000),
@@ -186,8 +529,9 @@ pub fn make_now(
     }
 }
 
-fn date_trunc_single(granularity: &str, value: i64) -> Result<i64> {
-    let value = timestamp_ns_to_datetime(value).with_nanosecond(0);
+/// Zeroes the sub-`granularity` fields of a (timezone-local) `NaiveDateTime`.
+fn truncate_naive(granularity: &str, value: NaiveDateTime) -> Result<NaiveDateTime> {
+    let value = value.with_nanosecond(0);
     let value = match granularity {
         "second" => value,
         "minute" => value.and_then(|d| d.with_second(0)),
@@ -222,7 +566,77 @@ fn date_trunc_single(granularity: &str, value: i64) -> Result<i64> {
         }
     };
     // `with_x(0)` are infalible because `0` are always a valid
-    Ok(value.unwrap().timestamp_nanos())
+    Ok(value.unwrap())
+}
+
+/// Resolves a local wall-clock time into an absolute instant, choosing the
+/// earliest candidate for ambiguous times (fall-back overlaps) and stepping
+/// forward out of nonexistent times (spring-forward gaps).
+fn resolve_local<T: TimeZone>(naive: NaiveDateTime, tz: &T) -> Result<DateTime<T>> {
+    match naive.and_local_timezone(tz.clone()) {
+        LocalResult::Single(dt) => Ok(dt),
+        LocalResult::Ambiguous(dt, _) => Ok(dt),
+        LocalResult::None => {
+            // nonexistent local time (DST gap); advance until the clock is valid
+            let mut candidate = naive;
+            for _ in 0..24 {
+                candidate += Duration::hours(1);
+                match candidate.and_local_timezone(tz.clone()) {
+                    LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => {
+                        return Ok(dt)
+                    }
+                    LocalResult::None => continue,
+                }
+            }
+            Err(DataFusionError::Execution(format!(
+                "Unable to resolve local time {} in the requested timezone",
+                naive
+            )))
+        }
+    }
+}
+
+/// Truncates the nanosecond instant `value` to `granularity` expressed in the
+/// wall-clock of `tz`, returning the resulting UTC nanosecond instant.
+fn truncate_in_tz<T: TimeZone>(
+    granularity: &str,
+    value: i64,
+    tz: &T,
+) -> Result<i64> {
+    let local = DateTime::<Utc>::from_utc(timestamp_ns_to_datetime(value), Utc)
+        .with_timezone(tz);
+    let truncated = truncate_naive(granularity, local.naive_local())?;
+    Ok(resolve_local(truncated, tz)?.timestamp_nanos())
+}
+
+/// Parses a fixed-offset timezone such as `+05:30` or `-08:00`.
+fn parse_fixed_offset(tz: &str) -> Result<FixedOffset> {
+    let err = || {
+        DataFusionError::Execution(format!("Unable to parse timezone '{}'", tz))
+    };
+    let (sign, rest) = match tz.chars().next() {
+        Some('+') => (1, &tz[1..]),
+        Some('-') => (-1, &tz[1..]),
+        _ => return Err(err()),
+    };
+    let mut parts = rest.split(':');
+    let hours: i32 = parts.next().ok_or_else(err)?.parse().map_err(|_| err())?;
+    let minutes: i32 = parts.next().unwrap_or("0").parse().map_err(|_| err())?;
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(err)
+}
+
+fn date_trunc_single(
+    granularity: &str,
+    value: i64,
+    tz_opt: &Option<String>,
+) -> Result<i64> {
+    match tz_opt {
+        Some(tz) => match tz.parse::<Tz>() {
+            Ok(tz) => truncate_in_tz(granularity, value, &tz),
+            Err(_) => truncate_in_tz(granularity, value, &parse_fixed_offset(tz)?),
+        },
+        None => truncate_in_tz(granularity, value, &Utc),
+    }
 }
 
 /// date_trunc SQL function
@@ -238,22 +652,31 @@ pub fn date_trunc(args: &[ColumnarValue]) -> Result<ColumnarValue> {
             ));
         };
 
-    let f = |x: Option<&i64>| x.map(|x| date_trunc_single(granularity, *x)).transpose();
-
     Ok(match array {
         ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(v, tz_opt)) => {
+            let value = v
+                .map(|x| date_trunc_single(granularity, x, tz_opt))
+                .transpose()?;
             ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
-                (f)(v.as_ref())?,
+                value,
                 tz_opt.clone(),
             ))
         }
         ColumnarValue::Array(array) => {
+            let tz_opt = match array.data_type() {
+                DataType::Timestamp(_, tz) => tz.clone(),
+                _ => None,
+            };
+            let f = |x: Option<&i64>| {
+                x.map(|x| date_trunc_single(granularity, *x, &tz_opt))
+                    .transpose()
+            };
             let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
             let array = array
                 .iter()
                 .map(f)
                 .collect::<Result<PrimitiveArray<i64>>>()?
-                .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+                .to(DataType::Timestamp(TimeUnit::Nanosecond, tz_opt.clone()));
 
             ColumnarValue::Array(Arc::new(array))
         }
@@ -265,6 +688,91 @@ pub fn date_trunc(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     })
 }
 
+/// Casts a `u32` temporal kernel result to the `Int32` arrays `date_part`
+/// returns, matching the existing `hour`/`year` handling.
+fn temporal_to_i32(array: PrimitiveArray<u32>) -> PrimitiveArray<i32> {
+    cast::primitive_to_primitive::<u32, i32>(&array, &DataType::Int32)
+}
+
+/// Converts a naive UTC instant into the wall-clock `NaiveDateTime` of
+/// `tz_opt`, so the `map_datetime` date parts observe the same local time the
+/// `temporal` kernels extract for a timezone-carrying column.
+fn to_local_naive(
+    naive_utc: NaiveDateTime,
+    tz_opt: &Option<String>,
+) -> Result<NaiveDateTime> {
+    match tz_opt {
+        None => Ok(naive_utc),
+        Some(tz) => {
+            let utc = DateTime::<Utc>::from_utc(naive_utc, Utc);
+            match tz.parse::<Tz>() {
+                Ok(tz) => Ok(utc.with_timezone(&tz).naive_local()),
+                Err(_) => {
+                    Ok(utc.with_timezone(&parse_fixed_offset(tz)?).naive_local())
+                }
+            }
+        }
+    }
+}
+
+/// The nanosecond-to-`NaiveDateTime` converter and timezone label for a
+/// `Timestamp` array.
+fn timestamp_converter(
+    array: &dyn Array,
+) -> Result<(fn(i64) -> NaiveDateTime, Option<String>)> {
+    match array.data_type() {
+        DataType::Timestamp(TimeUnit::Second, tz) => {
+            Ok((timestamp_s_to_datetime, tz.clone()))
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, tz) => {
+            Ok((timestamp_ms_to_datetime, tz.clone()))
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+            Ok((timestamp_us_to_datetime, tz.clone()))
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+            Ok((timestamp_ns_to_datetime, tz.clone()))
+        }
+        other => Err(DataFusionError::Execution(format!(
+            "Cannot extract date part from data type {:?}",
+            other
+        ))),
+    }
+}
+
+/// Maps each element of a `Timestamp` array to a value computed from its local
+/// `NaiveDateTime`, used for the date parts that have no dedicated temporal
+/// kernel. The column timezone is honored (matching the kernel-backed parts)
+/// and nulls are preserved.
+fn map_datetime<O, F>(array: &dyn Array, op: F) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType,
+    F: Fn(NaiveDateTime) -> O,
+{
+    let (convert, tz) = timestamp_converter(array)?;
+    let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+    array
+        .iter()
+        .map(|x| {
+            x.map(|v| Ok(op(to_local_naive(convert(*v), &tz)?)))
+                .transpose()
+        })
+        .collect::<Result<PrimitiveArray<O>>>()
+}
+
+/// Like [`map_datetime`] but reads the raw UTC instant without applying the
+/// column timezone, used by `epoch` which must return the absolute instant
+/// regardless of the timezone label (PostgreSQL semantics).
+fn map_instant<O, F>(array: &dyn Array, op: F) -> Result<PrimitiveArray<O>>
+where
+    O: NativeType,
+    F: Fn(NaiveDateTime) -> O,
+{
+    let (convert, _) = timestamp_converter(array)?;
+    let array = array.as_any().downcast_ref::<Int64Array>().unwrap();
+    Ok(array.iter().map(|x| x.map(|v| op(convert(*v)))).collect())
+}
+
 /// DATE_PART SQL function
 pub fn date_part(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     if args.len() != 2 {
@@ -289,23 +797,478 @@ pub fn date_part(args: &[ColumnarValue]) -> Result<ColumnarValue> {
         ColumnarValue::Scalar(scalar) => scalar.to_array(),
     };
 
-    let arr = match date_part.to_lowercase().as_str() {
-        "hour" => Ok(temporal::hour(array.as_ref())
-            .map(|x| cast::primitive_to_primitive::<u32, i32>(&x, &DataType::Int32))?),
-        "year" => Ok(temporal::year(array.as_ref())?),
-        _ => Err(DataFusionError::Execution(format!(
-            "Date part '{}' not supported",
-            date_part
+    let arr: ArrayRef = match date_part.to_lowercase().as_str() {
+        "hour" => Arc::new(temporal_to_i32(temporal::hour(array.as_ref())?)),
+        "minute" => Arc::new(temporal_to_i32(temporal::minute(array.as_ref())?)),
+        "second" => Arc::new(temporal_to_i32(temporal::second(array.as_ref())?)),
+        "millisecond" => Arc::new(map_datetime::<i32, _>(array.as_ref(), |d| {
+            (d.second() * 1_000 + d.nanosecond() / 1_000_000) as i32
+        })?),
+        "microsecond" => Arc::new(map_datetime::<i32, _>(array.as_ref(), |d| {
+            (d.second() * 1_000_000 + d.nanosecond() / 1_000) as i32
+        })?),
+        "day" => Arc::new(temporal_to_i32(temporal::day(array.as_ref())?)),
+        "doy" => Arc::new(map_datetime::<i32, _>(array.as_ref(), |d| {
+            d.ordinal() as i32
+        })?),
+        "dow" => Arc::new(map_datetime::<i32, _>(array.as_ref(), |d| {
+            d.weekday().num_days_from_sunday() as i32
+        })?),
+        "week" => Arc::new(temporal_to_i32(temporal::iso_week(array.as_ref())?)),
+        "month" => Arc::new(temporal_to_i32(temporal::month(array.as_ref())?)),
+        "quarter" => {
+            let month = temporal::month(array.as_ref())?;
+            let quarter: PrimitiveArray<i32> = month
+                .iter()
+                .map(|m| m.map(|m| ((*m as i32 - 1) / 3) + 1))
+                .collect();
+            Arc::new(quarter)
+        }
+        "year" => Arc::new(temporal::year(array.as_ref())?),
+        "epoch" => Arc::new(map_instant::<f64, _>(array.as_ref(), |d| {
+            d.timestamp() as f64 + d.timestamp_subsec_nanos() as f64 / 1_000_000_000.0
+        })?),
+        _ => {
+            return Err(DataFusionError::Execution(format!(
+                "Date part '{}' not supported",
+                date_part
+            )))
+        }
+    };
+
+    Ok(if is_scalar {
+        ColumnarValue::Scalar(ScalarValue::try_from_array(&arr, 0)?)
+    } else {
+        ColumnarValue::Array(arr)
+    })
+}
+
+/// Nanoseconds in a calendar day, used by the fixed-width datetime kernels.
+const NANOS_PER_DAY: i64 = 24 * 60 * 60 * 1_000_000_000;
+
+/// An interval broken into its calendar (`months`) and fixed (`days`, `nanos`)
+/// components, normalising the three DataFusion interval encodings.
+#[derive(Clone, Copy)]
+struct IntervalParts {
+    months: i32,
+    days: i64,
+    nanos: i64,
+}
+
+impl IntervalParts {
+    fn negated(self) -> Self {
+        Self {
+            months: -self.months,
+            days: -self.days,
+            nanos: -self.nanos,
+        }
+    }
+}
+
+/// Last day-of-month for the given year/month, used to clamp calendar addition
+/// (e.g. Jan 31 + 1 month => Feb 28/29).
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (y, m) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(y, m, 1)
+        .unwrap()
+        .pred_opt()
+        .unwrap()
+        .day()
+}
+
+/// Calendar-aware month addition: advances the year/month and clamps the day to
+/// the last valid day of the resulting month, preserving the time-of-day.
+fn add_months(dt: NaiveDateTime, months: i32) -> NaiveDateTime {
+    let total = dt.year() * 12 + dt.month0() as i32 + months;
+    let year = total.div_euclid(12);
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = dt.day().min(last_day_of_month(year, month));
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_time(dt.time())
+}
+
+/// Resolves a local wall-clock `NaiveDateTime` back into an absolute UTC
+/// nanosecond instant, honoring the operand's timezone label (DST included).
+fn local_naive_to_utc_nanos(
+    naive: NaiveDateTime,
+    tz_opt: &Option<String>,
+) -> Result<i64> {
+    match tz_opt {
+        None => Ok(naive.timestamp_nanos()),
+        Some(tz) => match tz.parse::<Tz>() {
+            Ok(tz) => Ok(resolve_local(naive, &tz)?.timestamp_nanos()),
+            Err(_) => Ok(resolve_local(naive, &parse_fixed_offset(tz)?)?
+                .timestamp_nanos()),
+        },
+    }
+}
+
+/// Applies an interval to a nanosecond instant: month/year components are added
+/// calendar-aware in the operand's timezone (so the local time-of-day is
+/// preserved across DST transitions), while day/time/nanosecond components are
+/// added as fixed durations.
+fn shift_nanos(
+    ts_ns: i64,
+    parts: IntervalParts,
+    tz_opt: &Option<String>,
+) -> Result<i64> {
+    let mut ns = ts_ns;
+    if parts.months != 0 {
+        let local = to_local_naive(timestamp_ns_to_datetime(ns), tz_opt)?;
+        ns = local_naive_to_utc_nanos(add_months(local, parts.months), tz_opt)?;
+    }
+    Ok(ns + parts.days * NANOS_PER_DAY + parts.nanos)
+}
+
+/// The (unit, timezone) of a timestamp operand, erroring for non-timestamps.
+fn timestamp_meta(value: &ColumnarValue) -> Result<(TimeUnit, Option<String>)> {
+    match value {
+        ColumnarValue::Array(a) => match a.data_type() {
+            DataType::Timestamp(unit, tz) => Ok((*unit, tz.clone())),
+            other => Err(DataFusionError::Execution(format!(
+                "Expected a timestamp argument, got {:?}",
+                other
+            ))),
+        },
+        ColumnarValue::Scalar(s) => match s {
+            ScalarValue::TimestampSecond(_, tz) => {
+                Ok((TimeUnit::Second, tz.clone()))
+            }
+            ScalarValue::TimestampMillisecond(_, tz) => {
+                Ok((TimeUnit::Millisecond, tz.clone()))
+            }
+            ScalarValue::TimestampMicrosecond(_, tz) => {
+                Ok((TimeUnit::Microsecond, tz.clone()))
+            }
+            ScalarValue::TimestampNanosecond(_, tz) => {
+                Ok((TimeUnit::Nanosecond, tz.clone()))
+            }
+            other => Err(DataFusionError::Execution(format!(
+                "Expected a timestamp argument, got {:?}",
+                other
+            ))),
+        },
+    }
+}
+
+fn is_timestamp(value: &ColumnarValue) -> bool {
+    timestamp_meta(value).is_ok()
+}
+
+fn interval_parts_from_scalar(s: &ScalarValue) -> Result<Option<IntervalParts>> {
+    Ok(match s {
+        ScalarValue::IntervalYearMonth(v) => v.map(|m| IntervalParts {
+            months: m,
+            days: 0,
+            nanos: 0,
+        }),
+        ScalarValue::IntervalDayTime(v) => v.map(|dm| IntervalParts {
+            months: 0,
+            days: dm.days() as i64,
+            nanos: dm.milliseconds() as i64 * 1_000_000,
+        }),
+        ScalarValue::IntervalMonthDayNano(v) => v.map(|m| IntervalParts {
+            months: m.months(),
+            days: m.days() as i64,
+            nanos: m.ns(),
+        }),
+        other => {
+            return Err(DataFusionError::Execution(format!(
+                "Expected an interval argument, got {:?}",
+                other
+            )))
+        }
+    })
+}
+
+fn interval_parts_from_array(
+    a: &dyn Array,
+    idx: usize,
+) -> Result<Option<IntervalParts>> {
+    match a.data_type() {
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            let a = a.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+            Ok((!a.is_null(idx)).then(|| IntervalParts {
+                months: a.value(idx),
+                days: 0,
+                nanos: 0,
+            }))
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let a = a.as_any().downcast_ref::<PrimitiveArray<days_ms>>().unwrap();
+            Ok((!a.is_null(idx)).then(|| {
+                let dm = a.value(idx);
+                IntervalParts {
+                    months: 0,
+                    days: dm.days() as i64,
+                    nanos: dm.milliseconds() as i64 * 1_000_000,
+                }
+            }))
+        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => {
+            let a = a
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            Ok((!a.is_null(idx)).then(|| {
+                let m = a.value(idx);
+                IntervalParts {
+                    months: m.months(),
+                    days: m.days() as i64,
+                    nanos: m.ns(),
+                }
+            }))
+        }
+        other => Err(DataFusionError::Execution(format!(
+            "Expected an interval argument, got {:?}",
+            other
         ))),
-    }?;
+    }
+}
+
+/// Physical `Int64` values behind a timestamp operand (scalars become a
+/// length-one array, matching `date_part`'s handling).
+fn timestamp_i64_array(value: &ColumnarValue) -> ArrayRef {
+    match value {
+        ColumnarValue::Array(a) => a.clone(),
+        ColumnarValue::Scalar(s) => s.to_array(),
+    }
+}
+
+/// Number of output rows for a binary datetime kernel, broadcasting scalars.
+fn broadcast_len(a: &ColumnarValue, b: &ColumnarValue) -> usize {
+    match (a, b) {
+        (ColumnarValue::Array(a), _) => a.len(),
+        (_, ColumnarValue::Array(b)) => b.len(),
+        _ => 1,
+    }
+}
+
+/// Shared implementation of `timestamp + interval` and `timestamp - interval`;
+/// `negate` flips the interval sign for subtraction. Preserves the timestamp's
+/// unit and timezone and propagates nulls from either operand.
+fn shift_timestamps(
+    ts: &ColumnarValue,
+    interval: &ColumnarValue,
+    negate: bool,
+) -> Result<ColumnarValue> {
+    let (unit, tz) = timestamp_meta(ts)?;
+    let is_scalar = matches!(ts, ColumnarValue::Scalar(_))
+        && matches!(interval, ColumnarValue::Scalar(_));
+    let len = broadcast_len(ts, interval);
+
+    let ts_array = timestamp_i64_array(ts);
+    let ts_array = ts_array.as_any().downcast_ref::<Int64Array>().unwrap();
+    let ts_is_array = matches!(ts, ColumnarValue::Array(_));
+
+    let mut values = Vec::with_capacity(len);
+    for idx in 0..len {
+        let ts_idx = if ts_is_array { idx } else { 0 };
+        let parts = match interval {
+            ColumnarValue::Scalar(s) => interval_parts_from_scalar(s)?,
+            ColumnarValue::Array(a) => interval_parts_from_array(a.as_ref(), idx)?,
+        };
+        let shifted = match (ts_array.is_null(ts_idx), parts) {
+            (false, Some(parts)) => {
+                let parts = if negate { parts.negated() } else { parts };
+                let ns = rescale_timestamp(
+                    ts_array.value(ts_idx),
+                    &unit,
+                    &TimeUnit::Nanosecond,
+                );
+                Some(rescale_timestamp(
+                    shift_nanos(ns, parts, &tz)?,
+                    &TimeUnit::Nanosecond,
+                    &unit,
+                ))
+            }
+            _ => None,
+        };
+        values.push(shifted);
+    }
+
+    let array = values
+        .into_iter()
+        .collect::<PrimitiveArray<i64>>()
+        .to(DataType::Timestamp(unit, tz.clone()));
+
+    Ok(if is_scalar {
+        ColumnarValue::Scalar(timestamp_scalar(
+            (!array.is_null(0)).then(|| array.value(0)),
+            &unit,
+            tz,
+        ))
+    } else {
+        ColumnarValue::Array(Arc::new(array))
+    })
+}
+
+/// `timestamp - timestamp`, yielding an `Interval(DayTime)` difference.
+fn timestamp_difference(
+    left: &ColumnarValue,
+    right: &ColumnarValue,
+) -> Result<ColumnarValue> {
+    let (lunit, _) = timestamp_meta(left)?;
+    let (runit, _) = timestamp_meta(right)?;
+    let is_scalar = matches!(left, ColumnarValue::Scalar(_))
+        && matches!(right, ColumnarValue::Scalar(_));
+    let len = broadcast_len(left, right);
+
+    let l_array = timestamp_i64_array(left);
+    let l_array = l_array.as_any().downcast_ref::<Int64Array>().unwrap();
+    let r_array = timestamp_i64_array(right);
+    let r_array = r_array.as_any().downcast_ref::<Int64Array>().unwrap();
+    let l_is_array = matches!(left, ColumnarValue::Array(_));
+    let r_is_array = matches!(right, ColumnarValue::Array(_));
+
+    let mut values = Vec::with_capacity(len);
+    for idx in 0..len {
+        let li = if l_is_array { idx } else { 0 };
+        let ri = if r_is_array { idx } else { 0 };
+        let diff = if l_array.is_null(li) || r_array.is_null(ri) {
+            None
+        } else {
+            let l_ns =
+                rescale_timestamp(l_array.value(li), &lunit, &TimeUnit::Nanosecond);
+            let r_ns =
+                rescale_timestamp(r_array.value(ri), &runit, &TimeUnit::Nanosecond);
+            let diff_ns = l_ns - r_ns;
+            let days = diff_ns.div_euclid(NANOS_PER_DAY);
+            let millis = (diff_ns - days * NANOS_PER_DAY) / 1_000_000;
+            Some(days_ms::new(days as i32, millis as i32))
+        };
+        values.push(diff);
+    }
+
+    let array = values
+        .into_iter()
+        .collect::<PrimitiveArray<days_ms>>()
+        .to(DataType::Interval(IntervalUnit::DayTime));
+
+    Ok(if is_scalar {
+        ColumnarValue::Scalar(ScalarValue::IntervalDayTime(
+            (!array.is_null(0)).then(|| array.value(0)),
+        ))
+    } else {
+        ColumnarValue::Array(Arc::new(array))
+    })
+}
+
+/// `timestamp + interval` kernel.
+pub fn timestamp_add(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() != 2 {
+        return Err(DataFusionError::Execution(
+            "timestamp_add expects exactly two arguments".to_string(),
+        ));
+    }
+    shift_timestamps(&args[0], &args[1], false)
+}
+
+/// `timestamp - interval` or `timestamp - timestamp` kernel; the latter yields
+/// an `Interval(DayTime)`.
+pub fn timestamp_sub(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() != 2 {
+        return Err(DataFusionError::Execution(
+            "timestamp_sub expects exactly two arguments".to_string(),
+        ));
+    }
+    if is_timestamp(&args[1]) {
+        timestamp_difference(&args[0], &args[1])
+    } else {
+        shift_timestamps(&args[0], &args[1], true)
+    }
+}
+
+/// date_bin SQL function: buckets `source` into fixed-width bins of `stride`
+/// aligned to `origin`, computing `origin + floor((source - origin) / stride) *
+/// stride`. Only fixed-width strides (day/time components, no month or year)
+/// are accepted; the source unit, timezone and nulls are preserved.
+pub fn date_bin(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+    if args.len() != 3 {
+        return Err(DataFusionError::Execution(
+            "date_bin expects exactly three arguments".to_string(),
+        ));
+    }
+    let (stride, source, origin) = (&args[0], &args[1], &args[2]);
+
+    // the stride must be a non-null, fixed-width interval scalar
+    let stride_parts = match stride {
+        ColumnarValue::Scalar(s) => interval_parts_from_scalar(s)?.ok_or_else(|| {
+            DataFusionError::Execution(
+                "date_bin stride must be a non-null interval".to_string(),
+            )
+        })?,
+        ColumnarValue::Array(_) => {
+            return Err(DataFusionError::Execution(
+                "date_bin stride must be a scalar interval".to_string(),
+            ))
+        }
+    };
+    if stride_parts.months != 0 {
+        return Err(DataFusionError::Execution(
+            "date_bin stride must be a fixed-width interval without a month or \
+             year component"
+                .to_string(),
+        ));
+    }
+    let stride_ns = stride_parts.days * NANOS_PER_DAY + stride_parts.nanos;
+    if stride_ns <= 0 {
+        return Err(DataFusionError::Execution(
+            "date_bin stride must be a positive interval".to_string(),
+        ));
+    }
+
+    // the origin must be a non-null timestamp scalar
+    let (origin_unit, _) = timestamp_meta(origin)?;
+    if matches!(origin, ColumnarValue::Array(_)) {
+        return Err(DataFusionError::Execution(
+            "date_bin origin must be a scalar timestamp".to_string(),
+        ));
+    }
+    let origin_array = timestamp_i64_array(origin);
+    let origin_array = origin_array.as_any().downcast_ref::<Int64Array>().unwrap();
+    if origin_array.is_null(0) {
+        return Err(DataFusionError::Execution(
+            "date_bin origin must be a non-null timestamp".to_string(),
+        ));
+    }
+    let origin_ns =
+        rescale_timestamp(origin_array.value(0), &origin_unit, &TimeUnit::Nanosecond);
+
+    let (source_unit, source_tz) = timestamp_meta(source)?;
+    let is_scalar = matches!(source, ColumnarValue::Scalar(_));
+    let source_array = timestamp_i64_array(source);
+    let source_array = source_array.as_any().downcast_ref::<Int64Array>().unwrap();
+
+    let array = source_array
+        .iter()
+        .map(|x| {
+            x.map(|v| {
+                let ns =
+                    rescale_timestamp(*v, &source_unit, &TimeUnit::Nanosecond);
+                // div_euclid floors toward negative infinity, so sources before
+                // the origin land in the correct (lower) bin
+                let binned =
+                    origin_ns + (ns - origin_ns).div_euclid(stride_ns) * stride_ns;
+                rescale_timestamp(binned, &TimeUnit::Nanosecond, &source_unit)
+            })
+        })
+        .collect::<PrimitiveArray<i64>>()
+        .to(DataType::Timestamp(source_unit, source_tz.clone()));
 
     Ok(if is_scalar {
-        ColumnarValue::Scalar(ScalarValue::try_from_array(
-            &(Arc::new(arr) as ArrayRef),
-            0,
-        )?)
+        ColumnarValue::Scalar(timestamp_scalar(
+            (!array.is_null(0)).then(|| array.value(0)),
+            &source_unit,
+            source_tz,
+        ))
     } else {
-        ColumnarValue::Array(Arc::new(arr))
+        ColumnarValue::Array(Arc::new(array))
     })
 }
 
@@ -395,22 +1358,279 @@ mod tests {
         cases.iter().for_each(|(original, granularity, expected)| {
             let original = string_to_timestamp_nanos(original).unwrap();
             let expected = string_to_timestamp_nanos(expected).unwrap();
-            let result = date_trunc_single(granularity, original).unwrap();
+            let result = date_trunc_single(granularity, original, &None).unwrap();
             assert_eq!(result, expected);
         });
     }
 
+    #[test]
+    fn date_trunc_timezone_aware() {
+        // truncating to the day in a -08:00 zone floors to the start of the
+        // *containing* local day. 06:30 UTC is 2020-09-07 22:30 -08:00, so the
+        // local day start is 2020-09-07 00:00 -08:00 = 2020-09-07T08:00:00Z.
+        let original =
+            string_to_timestamp_nanos("2020-09-08T06:30:00.000000Z").unwrap();
+        let expected =
+            string_to_timestamp_nanos("2020-09-07T08:00:00.000000Z").unwrap();
+        let result =
+            date_trunc_single("day", original, &Some("-08:00".to_string())).unwrap();
+        assert_eq!(result, expected);
+
+        // named IANA zones are honored too
+        let expected_utc =
+            string_to_timestamp_nanos("2020-09-08T00:00:00.000000Z").unwrap();
+        let result_utc =
+            date_trunc_single("day", original, &Some("UTC".to_string())).unwrap();
+        assert_eq!(result_utc, expected_utc);
+    }
+
+    #[test]
+    fn date_bin_buckets_to_stride() -> Result<()> {
+        // 15-minute bins aligned to the Unix epoch
+        let origin = ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+            Some(0),
+            None,
+        ));
+        let stride =
+            ColumnarValue::Scalar(ScalarValue::IntervalDayTime(Some(days_ms::new(
+                0,
+                15 * 60 * 1_000,
+            ))));
+
+        let source_ts =
+            string_to_timestamp_nanos("2020-09-08T13:42:29.190855Z").unwrap();
+        let expected =
+            string_to_timestamp_nanos("2020-09-08T13:30:00.000000Z").unwrap();
+
+        let result = date_bin(&[
+            stride,
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                Some(source_ts),
+                None,
+            )),
+            origin,
+        ])?;
+        match result {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(v), _)) => {
+                assert_eq!(v, expected)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn date_bin_rejects_month_stride() {
+        let result = date_bin(&[
+            ColumnarValue::Scalar(ScalarValue::IntervalYearMonth(Some(1))),
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(0), None)),
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(0), None)),
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn timestamp_plus_year_month_clamps_day() -> Result<()> {
+        // Jan 31 + 1 month => Feb 28 (2021 is not a leap year)
+        let ts =
+            string_to_timestamp_nanos("2021-01-31T10:00:00.000000Z").unwrap();
+        let expected =
+            string_to_timestamp_nanos("2021-02-28T10:00:00.000000Z").unwrap();
+
+        let result = timestamp_add(&[
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(ts), None)),
+            ColumnarValue::Scalar(ScalarValue::IntervalYearMonth(Some(1))),
+        ])?;
+        match result {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(v), _)) => {
+                assert_eq!(v, expected)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_plus_month_preserves_local_time_across_dst() -> Result<()> {
+        // 2021-02-15 10:00 America/Los_Angeles (PST, -08:00) is 18:00Z. Adding a
+        // month lands on 2021-03-15, after the DST switch (PDT, -07:00), so the
+        // local 10:00 is 17:00Z — one hour earlier in UTC than a naive shift.
+        let ts = string_to_timestamp_nanos("2021-02-15T18:00:00.000000Z").unwrap();
+        let expected =
+            string_to_timestamp_nanos("2021-03-15T17:00:00.000000Z").unwrap();
+
+        let result = timestamp_add(&[
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                Some(ts),
+                Some("America/Los_Angeles".to_string()),
+            )),
+            ColumnarValue::Scalar(ScalarValue::IntervalYearMonth(Some(1))),
+        ])?;
+        match result {
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(v), tz)) => {
+                assert_eq!(v, expected);
+                assert_eq!(tz, Some("America/Los_Angeles".to_string()));
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_minus_timestamp_is_daytime_interval() -> Result<()> {
+        let a = string_to_timestamp_nanos("2020-09-10T00:00:00.000000Z").unwrap();
+        let b = string_to_timestamp_nanos("2020-09-08T00:00:00.000000Z").unwrap();
+
+        let result = timestamp_sub(&[
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(a), None)),
+            ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(Some(b), None)),
+        ])?;
+        match result {
+            ColumnarValue::Scalar(ScalarValue::IntervalDayTime(Some(dm))) => {
+                assert_eq!(dm.days(), 2);
+                assert_eq!(dm.milliseconds(), 0);
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn date_part_fields() -> Result<()> {
+        let ts = string_to_timestamp_nanos("2020-09-08T13:42:29.190855Z").unwrap();
+        let make = |part: &str| -> Result<ScalarValue> {
+            date_part(&[
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(part.to_string()))),
+                ColumnarValue::Scalar(ScalarValue::TimestampNanosecond(
+                    Some(ts),
+                    None,
+                )),
+            ])
+            .and_then(|v| match v {
+                ColumnarValue::Scalar(s) => Ok(s),
+                _ => panic!("expected scalar"),
+            })
+        };
+
+        assert_eq!(make("minute")?, ScalarValue::Int32(Some(42)));
+        assert_eq!(make("second")?, ScalarValue::Int32(Some(29)));
+        assert_eq!(make("day")?, ScalarValue::Int32(Some(8)));
+        assert_eq!(make("month")?, ScalarValue::Int32(Some(9)));
+        assert_eq!(make("quarter")?, ScalarValue::Int32(Some(3)));
+        // 2020-09-08 is a Tuesday => day-of-week 2 (Sunday == 0)
+        assert_eq!(make("dow")?, ScalarValue::Int32(Some(2)));
+        assert_eq!(make("doy")?, ScalarValue::Int32(Some(252)));
+        assert_eq!(make("millisecond")?, ScalarValue::Int32(Some(29190)));
+        Ok(())
+    }
+
+    #[test]
+    fn date_part_honors_column_timezone() -> Result<()> {
+        // 2020-09-08T06:30:00Z is 2020-09-07 22:30 in -08:00, i.e. still the
+        // 7th locally. Both a kernel-backed part (`day`) and a map_datetime
+        // part (`doy`) must agree on the local calendar day.
+        let ts = string_to_timestamp_nanos("2020-09-08T06:30:00.000000Z").unwrap();
+        let column = ScalarValue::TimestampNanosecond(
+            Some(ts),
+            Some("-08:00".to_string()),
+        );
+        let part = |name: &str| -> Result<ScalarValue> {
+            date_part(&[
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(name.to_string()))),
+                ColumnarValue::Scalar(column.clone()),
+            ])
+            .and_then(|v| match v {
+                ColumnarValue::Scalar(s) => Ok(s),
+                _ => panic!("expected scalar"),
+            })
+        };
+
+        assert_eq!(part("day")?, ScalarValue::Int32(Some(7)));
+        // 2020-09-07 is the 251st day of the year
+        assert_eq!(part("doy")?, ScalarValue::Int32(Some(251)));
+        // `epoch` is the absolute instant and must ignore the tz label
+        assert_eq!(part("epoch")?, ScalarValue::Float64(Some(1599546600.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn to_timestamp_with_formats() -> Result<()> {
+        // custom, non-RFC3339 inputs are parsed using the trailing formats
+        let string_array = Utf8Array::<i32>::from(&[
+            Some("2020-09-08 13:42:29"),
+            Some("08/09/2020 13:42:29"),
+            None,
+        ]);
+
+        let expected = Int64Array::from(&[
+            Some(string_to_timestamp_nanos("2020-09-08T13:42:29Z").unwrap()),
+            Some(string_to_timestamp_nanos("2020-09-08T13:42:29Z").unwrap()),
+            None,
+        ])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+
+        let args = vec![
+            ColumnarValue::Array(Arc::new(string_array) as ArrayRef),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+                "%Y-%m-%d %H:%M:%S".to_string(),
+            ))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some(
+                "%d/%m/%Y %H:%M:%S".to_string(),
+            ))),
+        ];
+
+        let parsed = to_timestamp(&args).expect("to_timestamp with formats");
+        if let ColumnarValue::Array(parsed_array) = parsed {
+            assert_eq!(parsed_array.len(), 3);
+            assert_eq!(&expected as &dyn Array, parsed_array.as_ref());
+        } else {
+            panic!("Expected a columnar array")
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn to_timestamp_from_int64_epoch() -> Result<()> {
+        // Int64 inputs are reinterpreted as raw epochs in the target unit
+        let array = Int64Array::from(&[Some(1599572549190), None]);
+        let expected = Int64Array::from(&[Some(1599572549190), None])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+        let input = ColumnarValue::Array(Arc::new(array));
+        if let ColumnarValue::Array(parsed) = to_timestamp_millis(&[input])? {
+            assert_eq!(&expected as &dyn Array, parsed.as_ref());
+        } else {
+            panic!("Expected a columnar array")
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn to_timestamp_rescale_resolution() -> Result<()> {
+        // an existing microsecond timestamp is rescaled down to seconds
+        let micros =
+            ScalarValue::TimestampMicrosecond(Some(1599572549190855), None);
+        let result = to_timestamp_seconds(&[ColumnarValue::Scalar(micros)])?;
+        match result {
+            ColumnarValue::Scalar(ScalarValue::TimestampSecond(Some(v), _)) => {
+                assert_eq!(v, 1599572549);
+            }
+            other => panic!("Expected TimestampSecond scalar, got {:?}", other),
+        }
+        Ok(())
+    }
+
     #[test]
     fn to_timestamp_invalid_input_type() -> Result<()> {
-        // pass the wrong type of input array to to_timestamp and test
+        // pass an unsupported type of input array to to_timestamp and test
         // that we get an error.
 
-        let array = Int64Array::from_slice(&[1]);
-        let int64array = ColumnarValue::Array(Arc::new(array));
+        let array = Float64Array::from_slice(&[1.0]);
+        let float64array = ColumnarValue::Array(Arc::new(array));
 
         let expected_err =
-            "Internal error: Unsupported data type Int64 for function to_timestamp";
-        match to_timestamp(&[int64array]) {
+            "Internal error: Unsupported data type Float64 for function to_timestamp";
+        match to_timestamp(&[float64array]) {
             Ok(_) => panic!("Expected error but got success"),
             Err(e) => {
                 assert!(